@@ -0,0 +1,58 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use rustlifesim::brain::{prune, Brain, Connection};
+use rustlifesim::definitions::*;
+use rustlifesim::genome::Genome;
+use std::hint::black_box;
+
+/// Number of brains evaluated per iteration, as one simulation step of a population
+const POPULATION_SIZES: [usize; 2] = [100, 1000];
+/// Number of Genes behind each brain, as a multiple of GENOME_SIZE
+const GENOMES_PER_BRAIN: [usize; 3] = [1, 4, 16];
+
+/// Builds one brain per creature from the Connections of genomes_per_brain seeded random Genomes,
+/// pruned or not. With a single Genome this is Brain::from_genome against Brain::new(decode()).
+fn build_brains(population: usize, genomes_per_brain: usize, pruned: bool) -> Vec<Brain> {
+    let mut rng: StdRng = StdRng::seed_from_u64(0);
+    (0..population)
+        .map(|_| {
+            let connections: Vec<Connection> = (0..genomes_per_brain)
+                .flat_map(|_| Genome::new_random(&mut rng).decode())
+                .collect();
+            if pruned {
+                Brain::new(prune(&connections))
+            } else {
+                Brain::new(connections)
+            }
+        })
+        .collect()
+}
+
+/// Compares one evaluation step of a population of pruned brains against the same brains unpruned.
+fn bench_evaluate(c: &mut Criterion) {
+    let sensor_inputs: Vec<f32> = (0..SENSOR_COUNT)
+        .map(|idx| idx as f32 / SENSOR_COUNT as f32)
+        .collect();
+    let mut group = c.benchmark_group("brain_evaluate");
+    for population in POPULATION_SIZES {
+        for genomes_per_brain in GENOMES_PER_BRAIN {
+            let n_genes: usize = genomes_per_brain * GENOME_SIZE as usize;
+            let parameter: String = format!("{population}x{n_genes}");
+            for (name, pruned) in [("pruned", true), ("unpruned", false)] {
+                let mut brains: Vec<Brain> = build_brains(population, genomes_per_brain, pruned);
+                group.bench_function(BenchmarkId::new(name, &parameter), |b| {
+                    b.iter(|| {
+                        for brain in brains.iter_mut() {
+                            black_box(brain.evaluate(black_box(&sensor_inputs)));
+                        }
+                    })
+                });
+            }
+        }
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_evaluate);
+criterion_main!(benches);
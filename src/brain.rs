@@ -0,0 +1,521 @@
+use crate::definitions::*;
+use crate::genome::{fnv1a, Gene, GeneLayout, Genome};
+use std::collections::HashSet;
+
+/// We define a Connection as the decoded form of a Gene: a link from a source neuron to a sink 
+/// neuron, with the weight applied to the source output and the bias added at the sink. Neuron ids 
+/// are carried over as they are from the Gene bytes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Connection {
+    /// Source neuron id
+    pub source: u8,
+    /// Sink (destination) neuron id
+    pub sink: u8,
+    /// Weight applied to the source neuron output
+    pub weight: f32,
+    /// Bias added at the sink neuron
+    pub bias: f32
+}
+
+/// Kind and index of a neuron, resolved from a raw source or sink id following the id ranges in 
+/// definitions.rs. Sensors can only be sources and actions can only be sinks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Neuron {
+    /// Sensor neuron, with index in 0..SENSOR_COUNT
+    Sensor(u8),
+    /// Internal neuron, with index in 0..INTERNAL_NEURON_COUNT
+    Internal(u8),
+    /// Action neuron, with index in 0..ACTION_COUNT
+    Action(u8),
+}
+
+impl Neuron {
+    /// Constructor of the Neuron a raw source id refers to (a sensor or an internal neuron).
+    pub fn from_source_id(id: u8) -> Self {
+        if id & NEURON_INTERNAL_FLAG != 0 {
+            Neuron::Internal((id & !NEURON_INTERNAL_FLAG) % INTERNAL_NEURON_COUNT)
+        } else {
+            Neuron::Sensor(id % SENSOR_COUNT)
+        }
+    }
+
+    /// Constructor of the Neuron a raw sink id refers to (an action or an internal neuron).
+    pub fn from_sink_id(id: u8) -> Self {
+        if id & NEURON_INTERNAL_FLAG != 0 {
+            Neuron::Internal((id & !NEURON_INTERNAL_FLAG) % INTERNAL_NEURON_COUNT)
+        } else {
+            Neuron::Action(id % ACTION_COUNT)
+        }
+    }
+}
+
+/// How the Genes of a Genome are turned into Connections.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GeneInterpretation {
+    /// Every Gene is a Connection, with its weight and bias bytes decoded by decode_byte
+    #[default]
+    Plain,
+    /// The bias MSB (GENE_DISABLED_FLAG) silences the Gene: disabled Genes give no Connection, and 
+    /// enabled ones decode the 7 remaining bias bits with decode_bits, at reduced precision
+    WithEnableBit,
+    /// Weights are decoded as with Plain and then jittered within their quantization bucket (see 
+    /// jitter_weight), so equal weight bytes do not all land on the same value
+    StochasticDequant,
+}
+
+/// Maps a weight or bias byte linearly onto [-CONNECTION_WEIGHT_MAX, CONNECTION_WEIGHT_MAX], as 
+/// -MAX + 2 * MAX * byte / 255. Hence 0 decodes to -MAX, 255 to MAX, and consecutive bytes are 
+/// 2 * MAX / 255 apart (there is no byte decoding to exactly 0.0).
+pub fn decode_byte(byte: u8) -> f32 {
+    decode_bits(byte as u32, 8)
+}
+
+/// Moves a decoded weight within its quantization bucket, the interval of width 2 * MAX / 255 
+/// centered on it, clamped to [-MAX, MAX]. The offset comes from the FNV-1a hash of the Gene position 
+/// and unique value instead of a random number generator, so a Brain stays a pure function of its 
+/// Genome: the same Gene at the same position always gets the same offset.
+pub fn jitter_weight(weight: f32, gene_value: u32, position: usize) -> f32 {
+    let bucket_width: f32 = 2.0 * CONNECTION_WEIGHT_MAX / u8::MAX as f32;
+    let position_bytes: [u8; 8] = (position as u64).to_be_bytes();
+    let hash: u64 = fnv1a(position_bytes.into_iter().chain(gene_value.to_be_bytes()));
+    // The top 24 bits give a fraction in [0, 1) exactly representable as an f32
+    let fraction: f32 = (hash >> 40) as f32 / (1_u32 << 24) as f32;
+    let jittered: f32 = weight + (fraction - 0.5) * bucket_width;
+    jittered.clamp(-CONNECTION_WEIGHT_MAX, CONNECTION_WEIGHT_MAX)
+}
+
+/// Maps a field of the given number of bits linearly onto [-CONNECTION_WEIGHT_MAX, 
+/// CONNECTION_WEIGHT_MAX], as decode_byte does for 8 bits: 0 decodes to -MAX and the largest field 
+/// value, 2^bits - 1, to MAX.
+pub fn decode_bits(field: u32, bits: u32) -> f32 {
+    let field_max: u32 = ((1_u64 << bits) - 1) as u32;
+    -CONNECTION_WEIGHT_MAX + 2.0 * CONNECTION_WEIGHT_MAX * field as f32 / field_max as f32
+}
+
+impl Connection {
+    /// Constructor of a Connection from the four bytes of a Gene (source, weight, bias, sink), 
+    /// decoding the weight and bias with decode_byte.
+    pub fn new_from_bytes(bytes: [u8; 4]) -> Self {
+        Connection {
+            source: bytes[0],
+            sink: bytes[3],
+            weight: decode_byte(bytes[1]),
+            bias: decode_byte(bytes[2]),
+        }
+    }
+
+    /// Trait to return the Neuron the source id refers to.
+    pub fn source_neuron(&self) -> Neuron {
+        Neuron::from_source_id(self.source)
+    }
+
+    /// Trait to return the Neuron the sink id refers to.
+    pub fn sink_neuron(&self) -> Neuron {
+        Neuron::from_sink_id(self.sink)
+    }
+}
+
+/// Removes the Connections that cannot affect any action, following the biosim4 approach. An 
+/// internal neuron is kept only if it drives an action, directly or through other kept internal 
+/// neurons; this is found iteratively, growing the set of useful neurons backwards from the 
+/// actions until it does not change. A self-loop alone does not make a neuron useful, but the 
+/// self-loops of useful neurons are kept. Every Connection from or to a removed neuron is dropped, 
+/// and the remaining ones keep their original order.
+pub fn prune(connections: &[Connection]) -> Vec<Connection> {
+    let mut useful: HashSet<Neuron> = HashSet::new();
+    loop {
+        let n_useful: usize = useful.len();
+        for connection in connections.iter() {
+            let source: Neuron = connection.source_neuron();
+            let sink: Neuron = connection.sink_neuron();
+            let drives_action: bool = matches!(sink, Neuron::Action(_)) || useful.contains(&sink);
+            if matches!(source, Neuron::Internal(_)) && source != sink && drives_action {
+                useful.insert(source);
+            }
+        }
+        if useful.len() == n_useful {
+            break;
+        }
+    }
+    connections.iter()
+        .filter(|connection| {
+            let source: Neuron = connection.source_neuron();
+            let sink: Neuron = connection.sink_neuron();
+            (matches!(source, Neuron::Sensor(_)) || useful.contains(&source))
+                && (matches!(sink, Neuron::Action(_)) || useful.contains(&sink))
+        })
+        .cloned()
+        .collect()
+}
+
+/// We define a Brain as a set of pruned Connections stored in evaluation order, plus the outputs of 
+/// its internal neurons from the last evaluation. Evaluating runs every Connection into an internal 
+/// neuron first, then updates the internal neurons, and finally runs every Connection into an 
+/// action. Hence, connections between internal neurons (self-loops included) read the outputs from 
+/// the previous evaluation, while connections from internal neurons into actions read the ones just 
+/// computed. Every driven neuron outputs tanh of the sum of weight * input + bias over its incoming 
+/// Connections. The accumulators used while evaluating are kept in the Brain and reset on each 
+/// evaluation, so evaluating does not allocate them again at every step.
+#[derive(Debug, Clone)]
+pub struct Brain {
+    /// Connections into internal neurons first, then connections into actions
+    connections: Vec<Connection>,
+    /// Output of each internal neuron after the last evaluation
+    internal_outputs: Vec<f32>,
+    /// Sum of the incoming contributions of each internal neuron during an evaluation
+    internal_sums: Vec<f32>,
+    /// Whether each internal neuron has incoming Connections during an evaluation
+    internal_driven: Vec<bool>,
+    /// Sum of the incoming contributions of each action during an evaluation
+    action_sums: Vec<f32>,
+    /// Whether each action has incoming Connections during an evaluation
+    action_driven: Vec<bool>
+}
+
+impl Brain {
+    /// Constructor of a Brain from already pruned Connections. Internal neurons start at 0.0.
+    pub fn new(connections: Vec<Connection>) -> Self {
+        let mut connections: Vec<Connection> = connections;
+        // Stable sort, so connections into the same kind of neuron keep their relative order
+        connections.sort_by_key(|connection| matches!(connection.sink_neuron(), Neuron::Action(_)));
+        Brain {
+            connections,
+            internal_outputs: vec![0.0; INTERNAL_NEURON_COUNT as usize],
+            internal_sums: vec![0.0; INTERNAL_NEURON_COUNT as usize],
+            internal_driven: vec![false; INTERNAL_NEURON_COUNT as usize],
+            action_sums: vec![0.0; ACTION_COUNT as usize],
+            action_driven: vec![false; ACTION_COUNT as usize],
+        }
+    }
+
+    /// Constructor of a Brain from a Genome, decoding and pruning its Connections.
+    pub fn from_genome(genome: &Genome) -> Self {
+        Brain::from_genome_with(genome, GeneInterpretation::default())
+    }
+
+    /// Constructor of a Brain from a Genome, decoding its Connections with the given interpretation 
+    /// and pruning them.
+    pub fn from_genome_with(genome: &Genome, interpretation: GeneInterpretation) -> Self {
+        Brain::new(prune(&genome.decode_with(interpretation)))
+    }
+
+    /// Trait to return the Connections of the Brain, in evaluation order.
+    pub fn connections(&self) -> &[Connection] {
+        &self.connections
+    }
+
+    /// Trait to evaluate the Brain for the given sensor values (indexed as Neuron::Sensor, missing 
+    /// sensors read 0.0), returning one value per action. Actions without incoming Connections 
+    /// output 0.0, and internal neurons without them keep their previous output.
+    pub fn evaluate(&mut self, sensor_inputs: &[f32]) -> Vec<f32> {
+        self.internal_sums.fill(0.0);
+        self.internal_driven.fill(false);
+        self.action_sums.fill(0.0);
+        self.action_driven.fill(false);
+        let mut internal_updated: bool = false;
+        for connection in self.connections.iter() {
+            let sink: Neuron = connection.sink_neuron();
+            if !internal_updated && matches!(sink, Neuron::Action(_)) {
+                let outputs: &mut [f32] = &mut self.internal_outputs;
+                Brain::update_internal_outputs(outputs, &self.internal_sums, &self.internal_driven);
+                internal_updated = true;
+            }
+            let input: f32 = match connection.source_neuron() {
+                Neuron::Sensor(idx) => sensor_inputs.get(idx as usize).copied().unwrap_or(0.0),
+                Neuron::Internal(idx) => self.internal_outputs[idx as usize],
+                Neuron::Action(_) => unreachable!("actions are never sources"),
+            };
+            let contribution: f32 = connection.weight * input + connection.bias;
+            match sink {
+                Neuron::Internal(idx) => {
+                    self.internal_sums[idx as usize] += contribution;
+                    self.internal_driven[idx as usize] = true;
+                },
+                Neuron::Action(idx) => {
+                    self.action_sums[idx as usize] += contribution;
+                    self.action_driven[idx as usize] = true;
+                },
+                Neuron::Sensor(_) => unreachable!("sensors are never sinks"),
+            }
+        }
+        if !internal_updated {
+            let outputs: &mut [f32] = &mut self.internal_outputs;
+            Brain::update_internal_outputs(outputs, &self.internal_sums, &self.internal_driven);
+        }
+        self.action_sums.iter()
+            .zip(self.action_driven.iter())
+            .map(|(sum, driven)| if *driven { sum.tanh() } else { 0.0 })
+            .collect()
+    }
+
+    /// Sets the output of every driven internal neuron to tanh of its accumulated sum.
+    fn update_internal_outputs(outputs: &mut [f32], sums: &[f32], driven: &[bool]) {
+        for (neuron_idx, output) in outputs.iter_mut().enumerate() {
+            if driven[neuron_idx] {
+                *output = sums[neuron_idx].tanh();
+            }
+        }
+    }
+}
+
+/// Decodes the Gene at the given adn position with the given interpretation, reading it with the 
+/// default 8/8/8/8 GeneLayout. Returns None if the interpretation silences the Gene.
+fn decode_gene(gene: &Gene, position: usize, interpretation: GeneInterpretation) -> Option<Connection> {
+    let bytes: [u8; 4] = gene.fields(&GeneLayout::default()).map(|field| field as u8);
+    let mut connection: Connection = Connection::new_from_bytes(bytes);
+    match interpretation {
+        GeneInterpretation::Plain => {},
+        GeneInterpretation::WithEnableBit => {
+            if bytes[2] & GENE_DISABLED_FLAG != 0 {
+                return None;
+            }
+            connection.bias = decode_bits(bytes[2] as u32, 7);
+        },
+        GeneInterpretation::StochasticDequant => {
+            connection.weight = jitter_weight(connection.weight, gene.value(), position);
+        },
+    }
+    Some(connection)
+}
+
+impl Genome {
+    /// Trait to decode the Genome into its list of Connections, one per Gene in adn order. Genes 
+    /// are read with the default 8/8/8/8 GeneLayout.
+    pub fn decode(&self) -> Vec<Connection> {
+        self.decode_with(GeneInterpretation::Plain)
+    }
+
+    /// Trait to decode the Genome into its list of Connections with the given interpretation, in 
+    /// adn order. Genes are read with the default 8/8/8/8 GeneLayout.
+    pub fn decode_with(&self, interpretation: GeneInterpretation) -> Vec<Connection> {
+        self.adn().iter()
+            .enumerate()
+            .filter_map(|(position, gene)| decode_gene(gene, position, interpretation))
+            .collect()
+    }
+
+    /// Trait to count the Genes silenced by their bias MSB under GeneInterpretation::WithEnableBit. 
+    /// These Genes never become Connections, so they are not counted by pruning.
+    pub fn disabled_gene_count(&self) -> usize {
+        self.adn().iter().filter(|gene| gene.bias() & GENE_DISABLED_FLAG != 0).count()
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_decode_byte_bounds() {
+        assert_eq!(decode_byte(0), -CONNECTION_WEIGHT_MAX);
+        assert_eq!(decode_byte(255), CONNECTION_WEIGHT_MAX);
+        assert!(decode_byte(127) < 0.0);
+        assert!(decode_byte(128) > 0.0);
+    }
+
+    #[test]
+    fn test_connection_new_from_bytes() {
+        let connection: Connection = Connection::new_from_bytes([7, 0, 255, 9]);
+        assert_eq!(connection.source, 7);
+        assert_eq!(connection.sink, 9);
+        assert_eq!(connection.weight, -CONNECTION_WEIGHT_MAX);
+        assert_eq!(connection.bias, CONNECTION_WEIGHT_MAX);
+    }
+
+    #[test]
+    fn test_neuron_from_ids() {
+        assert_eq!(Neuron::from_source_id(3), Neuron::Sensor(3));
+        assert_eq!(Neuron::from_source_id(SENSOR_COUNT + 1), Neuron::Sensor(1));
+        assert_eq!(Neuron::from_source_id(NEURON_INTERNAL_FLAG | 2), Neuron::Internal(2));
+        assert_eq!(Neuron::from_sink_id(3), Neuron::Action(3));
+        assert_eq!(Neuron::from_sink_id(ACTION_COUNT + 1), Neuron::Action(1));
+        assert_eq!(Neuron::from_sink_id(0xFF), Neuron::Internal(0x7F % INTERNAL_NEURON_COUNT));
+    }
+
+    /// Builds a Connection between two raw ids with unit weight and no bias
+    fn link(source: u8, sink: u8) -> Connection {
+        Connection {source, sink, weight: 1.0, bias: 0.0}
+    }
+
+    #[test]
+    fn test_prune() {
+        let internal: u8 = NEURON_INTERNAL_FLAG;
+        let functional: Vec<Connection> = vec![
+            link(0, internal),                  // sensor 0 -> internal 0
+            link(internal, internal),           // internal 0 self-loop, kept as internal 0 is useful
+            link(internal, 1),                  // internal 0 -> action 1
+            link(internal | 3, internal),       // internal 3 -> internal 0 -> action 1
+            link(2, 2),                         // sensor 2 -> action 2
+        ];
+        let dangling: Vec<Connection> = vec![
+            link(1, internal | 1),              // sensor 1 -> internal 1, which drives nothing
+            link(internal | 1, internal | 1),   // internal 1 self-loop
+            link(internal | 4, internal | 5),   // internal 4 <-> internal 5 cycle without actions
+            link(internal | 5, internal | 4),
+            link(internal | 4, internal | 1),   // internal 4 -> dangling internal 1
+        ];
+        let mut connections: Vec<Connection> = functional.clone();
+        connections.extend(dangling);
+        assert_eq!(prune(&connections), functional);
+        assert_eq!(prune(&functional), functional);
+        assert!(prune(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_brain_single_connection() {
+        let connection: Connection = Connection::new_from_bytes([2, 200, 40, 5]);
+        let (weight, bias) = (connection.weight, connection.bias);
+        let mut brain: Brain = Brain::new(vec![connection]);
+        let sensor_inputs: Vec<f32> = vec![0.0, 0.0, 0.7];
+        let outputs: Vec<f32> = brain.evaluate(&sensor_inputs);
+        assert_eq!(outputs.len(), ACTION_COUNT as usize);
+        assert_eq!(outputs[5], (weight * 0.7 + bias).tanh());
+        for (action_idx, output) in outputs.iter().enumerate() {
+            if action_idx != 5 {
+                assert_eq!(*output, 0.0);
+            }
+        }
+    }
+
+    #[test]
+    fn test_brain_recurrent_connection() {
+        let internal: u8 = NEURON_INTERNAL_FLAG;
+        // Connections into the action come first to check the Brain reorders them
+        let mut brain: Brain = Brain::new(vec![
+            link(internal, 0),
+            link(internal, internal),
+            link(0, internal),
+        ]);
+        assert!(matches!(brain.connections()[2].sink_neuron(), Neuron::Action(0)));
+        let x: f32 = 0.3;
+        // First evaluation: the self-loop reads the initial 0.0 output of internal 0
+        let internal_first: f32 = x.tanh();
+        assert_eq!(brain.evaluate(&[x])[0], internal_first.tanh());
+        // Second evaluation: the self-loop reads the output of the first evaluation
+        let internal_second: f32 = (x + internal_first).tanh();
+        assert_eq!(brain.evaluate(&[x])[0], internal_second.tanh());
+    }
+
+    #[test]
+    fn test_brain_from_genome() {
+        let mut rng: StdRng = StdRng::seed_from_u64(0);
+        let genome: Genome = Genome::new_random(&mut rng);
+        let mut brain: Brain = Brain::from_genome(&genome);
+        assert_eq!(brain.connections().len(), prune(&genome.decode()).len());
+        let outputs: Vec<f32> = brain.evaluate(&[0.5; SENSOR_COUNT as usize]);
+        assert!(outputs.iter().all(|output| (-1.0..=1.0).contains(output)));
+    }
+
+    #[test]
+    fn test_brain_pruned_matches_unpruned() {
+        let mut rng: StdRng = StdRng::seed_from_u64(0);
+        let mut n_pruned: usize = 0;
+        for _ in 0..50 {
+            let genome: Genome = Genome::new_random(&mut rng);
+            let mut brain_pruned: Brain = Brain::from_genome(&genome);
+            let mut brain_unpruned: Brain = Brain::new(genome.decode());
+            if brain_pruned.connections().len() < brain_unpruned.connections().len() {
+                n_pruned += 1;
+            }
+            // Several steps, so recurrent internal neurons carry state between evaluations
+            for step in 0..5 {
+                let sensor_inputs: Vec<f32> = (0..SENSOR_COUNT)
+                    .map(|idx| (idx + step) as f32 / 10.0)
+                    .collect();
+                let outputs_pruned: Vec<f32> = brain_pruned.evaluate(&sensor_inputs);
+                assert_eq!(outputs_pruned, brain_unpruned.evaluate(&sensor_inputs));
+            }
+        }
+        assert!(n_pruned > 0);
+    }
+
+    #[test]
+    fn test_decode_bits() {
+        assert_eq!(decode_bits(0, 7), -CONNECTION_WEIGHT_MAX);
+        assert_eq!(decode_bits(127, 7), CONNECTION_WEIGHT_MAX);
+        assert!(decode_bits(63, 7) < 0.0);
+        assert!(decode_bits(64, 7) > 0.0);
+        for byte in 0..=u8::MAX {
+            assert_eq!(decode_bits(byte as u32, 8), decode_byte(byte));
+        }
+    }
+
+    #[test]
+    fn test_genome_decode_with_enable_bit() {
+        // Sensor 0 -> action 1 with bias bytes 0x00, 0x7F and 0x40 enabled, 0x80 and 0xFF disabled
+        let enabled: [u32; 3] = [0x0080_0001, 0x0080_7F01, 0x0080_4001];
+        let disabled: [u32; 2] = [0x0080_8001, 0x0080_FF01];
+        let mut values: Vec<u32> = enabled.to_vec();
+        values.extend(disabled.iter().cycle().take(GENOME_SIZE as usize - enabled.len()));
+        let genome: Genome = Genome::from_values(&values).unwrap();
+        assert_eq!(genome.decode().len(), GENOME_SIZE as usize);
+        assert_eq!(genome.disabled_gene_count(), GENOME_SIZE as usize - enabled.len());
+        let connections: Vec<Connection> = genome.decode_with(GeneInterpretation::WithEnableBit);
+        assert_eq!(connections.len(), enabled.len());
+        assert_eq!(connections[0].bias, -CONNECTION_WEIGHT_MAX);
+        assert_eq!(connections[1].bias, CONNECTION_WEIGHT_MAX);
+        assert_eq!(connections[2].bias, decode_bits(64, 7));
+        assert!(connections.iter().all(|connection| connection.weight == decode_byte(0x80)));
+        let brain: Brain = Brain::from_genome_with(&genome, GeneInterpretation::WithEnableBit);
+        assert_eq!(brain.connections().len(), enabled.len());
+        assert_eq!(Brain::from_genome(&genome).connections().len(), GENOME_SIZE as usize);
+    }
+
+    #[test]
+    fn test_genome_decode_with_all_disabled() {
+        let genome: Genome = Genome::from_values(&[0x0000_8000; GENOME_SIZE as usize]).unwrap();
+        assert_eq!(genome.disabled_gene_count(), GENOME_SIZE as usize);
+        assert!(genome.decode_with(GeneInterpretation::WithEnableBit).is_empty());
+        let mut rng: StdRng = StdRng::seed_from_u64(0);
+        let genome_random: Genome = Genome::new_random(&mut rng);
+        let n_enabled: usize = genome_random.decode_with(GeneInterpretation::WithEnableBit).len();
+        assert_eq!(n_enabled + genome_random.disabled_gene_count(), GENOME_SIZE as usize);
+    }
+
+    #[test]
+    fn test_genome_decode_stochastic_dequant() {
+        let bucket_half_width: f32 = CONNECTION_WEIGHT_MAX / u8::MAX as f32;
+        let mut rng: StdRng = StdRng::seed_from_u64(0);
+        for _ in 0..20 {
+            let genome: Genome = Genome::new_random(&mut rng);
+            let plain: Vec<Connection> = genome.decode();
+            let jittered: Vec<Connection> = genome.decode_with(GeneInterpretation::StochasticDequant);
+            assert_eq!(jittered, genome.decode_with(GeneInterpretation::StochasticDequant));
+            for (connection_plain, connection) in plain.iter().zip(jittered.iter()) {
+                assert!((connection.weight - connection_plain.weight).abs() <= bucket_half_width);
+                assert!(connection.weight.abs() <= CONNECTION_WEIGHT_MAX);
+                assert_eq!(connection.bias, connection_plain.bias);
+                assert_eq!(connection.source, connection_plain.source);
+                assert_eq!(connection.sink, connection_plain.sink);
+            }
+        }
+    }
+
+    #[test]
+    fn test_genome_decode_stochastic_dequant_by_position() {
+        let genome: Genome = Genome::from_values(&[0x0180_4002; GENOME_SIZE as usize]).unwrap();
+        let genome_copy: Genome = Genome::from_hex(&genome.to_hex()).unwrap();
+        let connections: Vec<Connection> = genome.decode_with(GeneInterpretation::StochasticDequant);
+        assert_eq!(connections, genome_copy.decode_with(GeneInterpretation::StochasticDequant));
+        for (position, connection) in connections.iter().enumerate().skip(1) {
+            assert_ne!(connection.weight, connections[0].weight, "position {position}");
+        }
+        let brain: Brain = Brain::from_genome_with(&genome, GeneInterpretation::StochasticDequant);
+        assert_eq!(brain.connections(), &connections[..]);
+    }
+
+    #[test]
+    fn test_genome_decode() {
+        let mut rng: StdRng = StdRng::seed_from_u64(0);
+        let genome: Genome = Genome::from_hex(&"0100ff02".repeat(GENOME_SIZE as usize)).unwrap();
+        let connections: Vec<Connection> = genome.decode();
+        assert_eq!(connections.len(), GENOME_SIZE as usize);
+        for connection in connections.iter() {
+            assert_eq!(*connection, Connection::new_from_bytes([1, 0, 255, 2]));
+        }
+        assert_eq!(Genome::new_random(&mut rng).decode().len(), GENOME_SIZE as usize);
+    }
+}
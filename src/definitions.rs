@@ -1,3 +1,23 @@
 pub const GENOME_SIZE: u8 = 16;
 pub const GENOME_MUTATION_TRIES: u16 = 1000;
-pub const GENOME_MUTATION_RATE: u16 = 1;  // Defined as odds per GENOME_MUTATION_TRIES
\ No newline at end of file
+pub const GENOME_MUTATION_RATE: u16 = 1;  // Defined as odds per GENOME_MUTATION_TRIES
+// Masks selecting each of the four fields inside the packed Gene value (big-endian, source first)
+pub const SOURCE_ID_BITMASK: u32 = 0xFF00_0000;
+pub const SOURCE_W_BITMASK: u32 = 0x00FF_0000;
+pub const SOURCE_B_BITMASK: u32 = 0x0000_FF00;
+pub const SINK_ID_BITMASK: u32 = 0x0000_00FF;
+// Offsets of each field inside the packed Gene value, to be used together with the masks above
+pub const SOURCE_ID_SHIFT: u32 = 24;
+pub const SOURCE_W_SHIFT: u32 = 16;
+pub const SOURCE_B_SHIFT: u32 = 8;
+pub const SINK_ID_SHIFT: u32 = 0;
+pub const CONNECTION_WEIGHT_MAX: f32 = 4.0;  // Decoded weights and biases lie in [-MAX, MAX]
+// Bias bit that silences a Gene when decoded with GeneInterpretation::WithEnableBit
+pub const GENE_DISABLED_FLAG: u8 = 0x80;
+// Neuron id ranges. As a source, ids 0x00-0x7F are sensors and 0x80-0xFF internal neurons. As a 
+// sink, ids 0x00-0x7F are actions and 0x80-0xFF internal neurons. Within its range, the id without 
+// the internal flag bit, modulo the number of neurons of that kind, gives the neuron index.
+pub const NEURON_INTERNAL_FLAG: u8 = 0x80;
+pub const SENSOR_COUNT: u8 = 8;
+pub const INTERNAL_NEURON_COUNT: u8 = 8;
+pub const ACTION_COUNT: u8 = 8;
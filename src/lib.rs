@@ -0,0 +1,3 @@
+pub mod brain;
+pub mod definitions;
+pub mod genome;
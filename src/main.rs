@@ -1,9 +1,6 @@
-#![allow(unused_doc_comments)]
-#![allow(dead_code)]
-
-mod genome;
-mod definitions;
+use rustlifesim::genome::Genome;
 
 
 fn main() {
+    Genome::new_random(&mut rand::thread_rng()).print();
 }
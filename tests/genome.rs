@@ -0,0 +1,58 @@
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use rustlifesim::brain::Connection;
+use rustlifesim::definitions::*;
+use rustlifesim::genome::{self, Gene, Genome, GenomeError};
+
+#[test]
+fn test_genome_public_construction() {
+    let values: Vec<u32> = (0..GENOME_SIZE as u32).map(|gene_idx| gene_idx << 8).collect();
+    let genome: Genome = Genome::from_values(&values).unwrap();
+    assert_eq!(genome.adn().len(), GENOME_SIZE as usize);
+    assert_eq!(genome.adn()[3].bias(), 3);
+    assert_eq!(genome.adn()[3].value(), 3 << 8);
+    assert!(matches!(Genome::from_values(&values[1..]), Err(GenomeError::WrongLength {..})));
+}
+
+#[test]
+fn test_genome_public_mutation() {
+    let mut rng: StdRng = StdRng::seed_from_u64(0);
+    let genome: Genome = Genome::new_random(&mut rng);
+    let mut genome_mutated: Genome = genome.clone();
+    genome_mutated.mutate_on_odds(0, &mut rng);
+    assert_eq!(genome_mutated, genome);
+    genome_mutated.mutate_deterministic(&mut rng);
+    assert_ne!(genome_mutated, genome);
+    assert_eq!(genome_mutated.id(), genome.id());
+}
+
+#[test]
+fn test_gene_public_setters() {
+    let mut gene: Gene = Gene::new_from_bytes([1, 2, 3, 4]);
+    gene.set_weight(200);
+    assert_eq!(gene.to_bytes(), [1, 200, 3, 4]);
+    assert_eq!(gene.value(), Gene::value_from_bytes([1, 200, 3, 4]));
+}
+
+#[test]
+fn test_genome_public_reproduction() {
+    let mut rng: StdRng = StdRng::seed_from_u64(0);
+    let parent_a: Genome = Genome::new_random(&mut rng);
+    let parent_b: Genome = Genome::new_random(&mut rng);
+    let child: Genome = Genome::reproduce(&parent_a, &parent_b, 0, &mut rng);
+    assert_ne!(child.id(), parent_a.id());
+    assert_eq!(child.adn().len(), GENOME_SIZE as usize);
+    let population: Vec<Genome> = vec![parent_a, parent_b, child];
+    assert!(genome::population_diversity(&population) > 0.0);
+}
+
+#[test]
+fn test_genome_public_serialization() {
+    let mut rng: StdRng = StdRng::seed_from_u64(0);
+    let genome: Genome = Genome::new_random(&mut rng);
+    let genome_hex: Genome = Genome::from_hex(&genome.to_hex()).unwrap();
+    assert_eq!(genome_hex, genome);
+    let connections: Vec<Connection> = genome.decode();
+    assert_eq!(connections.len(), genome.adn().len());
+    assert_eq!(connections[0].source, genome.adn()[0].source());
+}